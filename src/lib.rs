@@ -7,11 +7,14 @@
 //!
 //! ## Features
 //!
-//! - Categorizes `serde_json::Error` into specific error types (`Syntax`, `IO`, `Data`, `EOF`)
+//! - Categorizes `serde_json::Error` into specific error types (`Syntax`, `IO`, `Data`, `EOF`), further refined into finer kinds (trailing characters, invalid number, invalid escape, ...) when `serde_json`'s message recognizably identifies one
 //! - Each error type is associated with a custom code, HTTP status, and descriptive message
 //! - Structured output for APIs, logging systems, and observability platforms
-//! - Includes context metadata via `BTreeMap`
+//! - Includes context metadata via `BTreeMap`, including the `json.line`/`json.column` of the failure when available
 //! - Provides a convenient `convert_result!` macro for error conversion
+//! - Provides `JsonErrorConverter::convert_at_path` and a `convert_result_at!` macro that record the dotted `json.path` of the field that failed to deserialize
+//! - Optionally retains the raw offending input under `json.raw_input` via `JsonErrorConverter::convert_with_input` or the 4-argument form of `convert_result!`
+//! - Classifies each error as retriable or fatal via `JsonErrorConverter::is_recoverable` (on the source `serde_json::Error`) or `JsonErrorConverter::is_error_recoverable` (on the converted `Error`), recorded under the `json.retriable` context key, so middleware can automate retry/backoff without string-matching message ids
 //!
 //! ## Usage
 //!
@@ -51,6 +54,33 @@
 //!     convert_result!(serde_json::from_str::<Value>(input), ctx, "Failed to parse JSON")
 //! }
 //! ```
+//!
+//! Using the `convert_result_at!` macro to get the dotted path of the failing field:
+//! ```rust
+//! use cdumay_error_json::convert_result_at;
+//! use serde::Deserialize;
+//! use cdumay_core::Error;
+//!
+//! #[derive(Deserialize)]
+//! struct Dependency {
+//!     version: String,
+//! }
+//!
+//! fn parse_dependency(input: &str) -> Result<Dependency, Error> {
+//!     convert_result_at!(input, Dependency, Default::default(), "Failed to parse dependency".to_string())
+//! }
+//! ```
+//!
+//! Using the 4-argument form of `convert_result!` to retain the raw offending input:
+//! ```rust
+//! use cdumay_error_json::convert_result;
+//! use serde_json::Value;
+//! use cdumay_core::Error;
+//!
+//! fn parse_json(input: &str) -> Result<Value, Error> {
+//!     convert_result!(input, serde_json::from_str::<Value>(input), Default::default(), "Failed to parse JSON".to_string())
+//! }
+//! ```
 #[macro_use]
 mod macros;
 
@@ -60,6 +90,15 @@ use std::collections::BTreeMap;
 
 define_kinds! {
     JsonSyntax = (400, "Syntax Error"),
+    JsonTrailingCharacters = (400, "Unexpected trailing characters after the JSON value"),
+    JsonInvalidNumber = (400, "Invalid JSON number"),
+    JsonInvalidEscape = (400, "Invalid escape sequence in a JSON string"),
+    JsonLoneLeadingSurrogate = (400, "Lone leading surrogate in a JSON string"),
+    JsonKeyMustBeString = (400, "Object key must be a string"),
+    JsonExpectedColon = (400, "Expected ':' after an object key"),
+    JsonExpectedComma = (400, "Expected ',' between JSON elements"),
+    JsonEofWhileParsingString = (500, "Reached the end of the input while parsing a string"),
+    JsonEofWhileParsingObject = (500, "Reached the end of the input while parsing an object"),
     JsonData = (400, "Invalid JSON data"),
     JsonEof = (500, "Reached the end of the input data"),
     JsonIo = (500, "IO Error"),
@@ -68,6 +107,15 @@ define_kinds! {
 define_errors! {
     IoError = JsonIo,
     SyntaxError = JsonSyntax,
+    TrailingCharactersError = JsonTrailingCharacters,
+    InvalidNumberError = JsonInvalidNumber,
+    InvalidEscapeError = JsonInvalidEscape,
+    LoneLeadingSurrogateError = JsonLoneLeadingSurrogate,
+    KeyMustBeStringError = JsonKeyMustBeString,
+    ExpectedColonError = JsonExpectedColon,
+    ExpectedCommaError = JsonExpectedComma,
+    EofWhileParsingStringError = JsonEofWhileParsingString,
+    EofWhileParsingObjectError = JsonEofWhileParsingObject,
     DataError = JsonData,
     EofError = JsonEof
 }
@@ -79,6 +127,16 @@ impl ErrorConverter for JsonErrorConverter {
     type Error = serde_json::Error;
     /// Converts a `serde_json::Error` into a standardized `Error` type based on its category.
     ///
+    /// The source error's `line()`/`column()` are injected into `context` under the
+    /// `json.line` and `json.column` keys so structured logging and API output can pinpoint
+    /// where the parse failed. Both keys are omitted when `line()` and `column()` are both
+    /// zero, which happens for `Io` errors and other cases where no position is available.
+    ///
+    /// Within the `Syntax` and `Eof` categories, the error's `Display` text is inspected to
+    /// pick a more specific kind (trailing characters, an invalid number, an invalid escape,
+    /// ...) when one is recognized; otherwise this falls back to the coarse
+    /// `Syntax`/`Data`/`Eof`/`Io` kind, exactly as before.
+    ///
     /// # Arguments
     ///
     /// * `err` - The `serde_json::Error` to be converted.
@@ -88,12 +146,152 @@ impl ErrorConverter for JsonErrorConverter {
     /// # Returns
     ///
     /// A standardized `Error` instance corresponding to the category of the provided `serde_json::Error`.
-    fn convert(err: &serde_json::Error, text: String, context: BTreeMap<String, serde_value::Value>) -> Error {
+    fn convert(err: &serde_json::Error, text: String, mut context: BTreeMap<String, serde_value::Value>) -> Error {
+        let (line, column) = (err.line(), err.column());
+        if line != 0 || column != 0 {
+            context.insert("json.line".to_string(), serde_value::Value::U64(line as u64));
+            context.insert("json.column".to_string(), serde_value::Value::U64(column as u64));
+        }
+        context.insert("json.retriable".to_string(), serde_value::Value::Bool(JsonErrorConverter::is_recoverable(err)));
+        let message = err.to_string();
         match err.classify() {
             Category::Io => IoError::new().with_message(text).with_details(context).into(),
-            Category::Syntax => SyntaxError::new().with_message(text).with_details(context).into(),
+            Category::Syntax => {
+                if message.starts_with("trailing characters") {
+                    TrailingCharactersError::new().with_message(text).with_details(context).into()
+                } else if message.starts_with("invalid number") {
+                    InvalidNumberError::new().with_message(text).with_details(context).into()
+                } else if message.starts_with("invalid escape") {
+                    InvalidEscapeError::new().with_message(text).with_details(context).into()
+                } else if message.starts_with("lone leading surrogate") {
+                    LoneLeadingSurrogateError::new().with_message(text).with_details(context).into()
+                } else if message.starts_with("key must be a string") {
+                    KeyMustBeStringError::new().with_message(text).with_details(context).into()
+                } else if message.starts_with("expected `:`") {
+                    ExpectedColonError::new().with_message(text).with_details(context).into()
+                } else if message.starts_with("expected `,`") {
+                    ExpectedCommaError::new().with_message(text).with_details(context).into()
+                } else {
+                    SyntaxError::new().with_message(text).with_details(context).into()
+                }
+            }
             Category::Data => DataError::new().with_message(text).with_details(context).into(),
-            Category::Eof => EofError::new().with_message(text).with_details(context).into(),
+            Category::Eof => {
+                if message.starts_with("EOF while parsing a string") {
+                    EofWhileParsingStringError::new().with_message(text).with_details(context).into()
+                } else if message.starts_with("EOF while parsing an object") {
+                    EofWhileParsingObjectError::new().with_message(text).with_details(context).into()
+                } else {
+                    EofError::new().with_message(text).with_details(context).into()
+                }
+            }
         }
     }
 }
+
+impl JsonErrorConverter {
+    /// Classifies a `serde_json::Error` as retriable or fatal. `Eof` and `Io` categories are
+    /// considered recoverable; `Syntax` and `Data` are fatal. [`convert`](Self::convert) records
+    /// this under the `json.retriable` context key.
+    ///
+    /// # Arguments
+    ///
+    /// * `err` - The `serde_json::Error` to classify.
+    ///
+    /// # Returns
+    ///
+    /// `true` if retrying the same operation (e.g. re-fetching more input) could plausibly
+    /// succeed, `false` if the bytes already read are invalid JSON or do not match the expected
+    /// data and retrying them verbatim cannot help.
+    pub fn is_recoverable(err: &serde_json::Error) -> bool {
+        matches!(err.classify(), Category::Eof | Category::Io)
+    }
+
+    /// Classifies an already-converted `Error` as retriable or fatal, for callers that only
+    /// have the converted `Error` (e.g. after it crosses an API boundary) and not the original
+    /// `serde_json::Error`.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The converted `Error` to classify.
+    ///
+    /// # Returns
+    ///
+    /// The `json.retriable` flag that [`convert`](Self::convert) recorded on `error`, or `false`
+    /// if the key is missing (e.g. `error` wasn't produced by this converter).
+    pub fn is_error_recoverable(error: &Error) -> bool {
+        matches!(error.details.get("json.retriable"), Some(serde_value::Value::Bool(true)))
+    }
+
+    /// Deserializes `input` into `T`, converting a failure into a standardized `Error` that
+    /// carries the dotted path of the field where deserialization broke down. Like
+    /// `serde_json::from_str`, trailing characters after a structurally valid value are
+    /// rejected rather than silently ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The raw JSON text to deserialize.
+    /// * `text` - A descriptive message for the error.
+    /// * `context` - A `BTreeMap` containing additional error details.
+    ///
+    /// # Returns
+    ///
+    /// The deserialized `T`, or an `Error` whose `json.path` context key holds the dotted path
+    /// (map keys and sequence indices, e.g. `dependencies.serde.version`) of the failing field.
+    /// An error for trailing characters after a valid value carries no `json.path`.
+    pub fn convert_at_path<'de, T>(input: &'de str, text: String, mut context: BTreeMap<String, serde_value::Value>) -> Result<T, Error>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        let mut deserializer = serde_json::Deserializer::from_str(input);
+        let value = match serde_path_to_error::deserialize(&mut deserializer) {
+            Ok(value) => value,
+            Err(err) => {
+                let path = err.path().to_string();
+                context.insert("json.path".to_string(), serde_value::Value::String(path.clone()));
+                return Err(Self::convert(err.inner(), format!("{text} ({path})"), context));
+            }
+        };
+        if let Err(err) = deserializer.end() {
+            return Err(Self::convert(&err, text, context));
+        }
+        Ok(value)
+    }
+
+    /// Default number of bytes of the offending input retained by
+    /// [`convert_with_input`](Self::convert_with_input).
+    pub const DEFAULT_RAW_INPUT_LIMIT: usize = 2048;
+
+    /// Like [`convert`](Self::convert), but also preserves (a prefix of) the raw input that
+    /// failed to parse under the `json.raw_input` context key, so observability platforms can
+    /// show the malformed payload alongside the typed error.
+    ///
+    /// # Arguments
+    ///
+    /// * `err` - The `serde_json::Error` to be converted.
+    /// * `input` - The raw JSON text that failed to parse.
+    /// * `text` - A descriptive message for the error.
+    /// * `context` - A `BTreeMap` containing additional error details.
+    /// * `limit` - The maximum number of bytes of `input` to retain; pass
+    ///   [`DEFAULT_RAW_INPUT_LIMIT`](Self::DEFAULT_RAW_INPUT_LIMIT) for the default budget. The
+    ///   input is truncated on a `char` boundary so it never splits a multi-byte UTF-8 sequence.
+    ///
+    /// # Returns
+    ///
+    /// A standardized `Error` instance corresponding to the category of the provided `serde_json::Error`.
+    pub fn convert_with_input(err: &serde_json::Error, input: &str, text: String, mut context: BTreeMap<String, serde_value::Value>, limit: usize) -> Error {
+        context.insert("json.raw_input".to_string(), serde_value::Value::String(truncate_at_char_boundary(input, limit).to_string()));
+        Self::convert(err, text, context)
+    }
+}
+
+fn truncate_at_char_boundary(input: &str, limit: usize) -> &str {
+    if input.len() <= limit {
+        return input;
+    }
+    let mut end = limit;
+    while end > 0 && !input.is_char_boundary(end) {
+        end -= 1;
+    }
+    &input[..end]
+}
@@ -0,0 +1,64 @@
+/// Converts a `Result<T, serde_json::Error>` into a `Result<T, cdumay_core::Error>`, routing the
+/// `Err` variant through [`JsonErrorConverter::convert`](crate::JsonErrorConverter::convert).
+///
+/// Accepts one to three arguments, or four to also retain the raw offending input:
+///
+/// * `convert_result!(result)` — no extra context, default message.
+/// * `convert_result!(result, context)` — attaches a `BTreeMap` of context details.
+/// * `convert_result!(result, context, text)` — also overrides the error message.
+/// * `convert_result!(input_str, result, context, text)` — additionally stashes (a prefix of)
+///   `input_str` under the `json.raw_input` context key via
+///   [`JsonErrorConverter::convert_with_input`](crate::JsonErrorConverter::convert_with_input).
+#[macro_export]
+macro_rules! convert_result {
+    ($result:expr) => {
+        $crate::convert_result!($result, ::std::collections::BTreeMap::new())
+    };
+    ($result:expr, $context:expr) => {
+        $crate::convert_result!($result, $context, "Failed to process JSON data".to_string())
+    };
+    ($result:expr, $context:expr, $text:expr) => {
+        match $result {
+            Ok(value) => Ok(value),
+            Err(ref err) => Err(<$crate::JsonErrorConverter as cdumay_core::ErrorConverter>::convert(
+                err,
+                $text.to_string(),
+                $context,
+            )),
+        }
+    };
+    ($input_str:expr, $result:expr, $context:expr, $text:expr) => {
+        match $result {
+            Ok(value) => Ok(value),
+            Err(ref err) => Err($crate::JsonErrorConverter::convert_with_input(
+                err,
+                $input_str,
+                $text.to_string(),
+                $context,
+                $crate::JsonErrorConverter::DEFAULT_RAW_INPUT_LIMIT,
+            )),
+        }
+    };
+}
+
+/// Deserializes `$input` into `$ty`, converting a failure into a `cdumay_core::Error` that
+/// carries the dotted path of the field that failed via
+/// [`JsonErrorConverter::convert_at_path`](crate::JsonErrorConverter::convert_at_path).
+///
+/// Accepts two to four arguments:
+///
+/// * `convert_result_at!(input, Type)` — no extra context, default message.
+/// * `convert_result_at!(input, Type, context)` — attaches a `BTreeMap` of context details.
+/// * `convert_result_at!(input, Type, context, text)` — also overrides the error message.
+#[macro_export]
+macro_rules! convert_result_at {
+    ($input:expr, $ty:ty) => {
+        $crate::convert_result_at!($input, $ty, ::std::collections::BTreeMap::new())
+    };
+    ($input:expr, $ty:ty, $context:expr) => {
+        $crate::convert_result_at!($input, $ty, $context, "Failed to process JSON data".to_string())
+    };
+    ($input:expr, $ty:ty, $context:expr, $text:expr) => {
+        $crate::JsonErrorConverter::convert_at_path::<$ty>($input, $text.to_string(), $context)
+    };
+}
@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 use serde_json::Value;
-use cdumay_error_json::convert_result;
+use cdumay_error_json::{convert_result, convert_result_at, JsonErrorConverter};
 use cdumay_error::ErrorConverter;
 
 #[test]
@@ -45,3 +45,208 @@ fn test_convert_result_success() {
     let converted = convert_result!(result);
     assert!(converted.is_ok());
 }
+
+#[test]
+fn test_convert_result_captures_line_and_column() {
+    let result: Result<Value, serde_json::Error> = serde_json::from_str("{\"a\":1}x");
+    let (line, column) = match &result {
+        Err(err) => (err.line(), err.column()),
+        Ok(_) => panic!("expected a parse error"),
+    };
+
+    let converted = convert_result!(result);
+    let err = converted.unwrap_err();
+    assert_eq!(err.details.get("json.line"), Some(&serde_value::Value::U64(line as u64)));
+    assert_eq!(err.details.get("json.column"), Some(&serde_value::Value::U64(column as u64)));
+}
+
+#[test]
+fn test_convert_result_omits_line_and_column_for_io_error() {
+    struct FailingReader;
+    impl std::io::Read for FailingReader {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::new(std::io::ErrorKind::Other, "boom"))
+        }
+    }
+
+    let result: Result<Value, serde_json::Error> = serde_json::from_reader(FailingReader);
+    let err = result.as_ref().unwrap_err();
+    assert_eq!(err.classify(), serde_json::error::Category::Io);
+    assert_eq!(err.line(), 0);
+    assert_eq!(err.column(), 0);
+
+    let converted = convert_result!(result);
+    let err = converted.unwrap_err();
+    assert!(!err.details.contains_key("json.line"));
+    assert!(!err.details.contains_key("json.column"));
+}
+
+#[test]
+fn test_convert_result_trailing_characters() {
+    let result: Result<Value, serde_json::Error> = serde_json::from_str("{\"a\":1}x");
+    let converted = convert_result!(result);
+    assert!(converted.is_err());
+
+    let err = converted.unwrap_err();
+    assert_eq!(err.kind.message_id(), "JSON-00002");
+}
+
+#[test]
+fn test_convert_result_invalid_number() {
+    let result: Result<Value, serde_json::Error> = serde_json::from_str("{\"a\":01}");
+    let converted = convert_result!(result);
+    assert!(converted.is_err());
+
+    let err = converted.unwrap_err();
+    assert_eq!(err.kind.message_id(), "JSON-00003");
+}
+
+#[test]
+fn test_convert_result_invalid_escape() {
+    let result: Result<Value, serde_json::Error> = serde_json::from_str("{\"a\":\"\\q\"}");
+    let converted = convert_result!(result);
+    assert!(converted.is_err());
+
+    let err = converted.unwrap_err();
+    assert_eq!(err.kind.message_id(), "JSON-00004");
+}
+
+#[test]
+fn test_convert_result_eof_while_parsing_string() {
+    let result: Result<Value, serde_json::Error> = serde_json::from_str("{\"a\":\"unterminated");
+    let converted = convert_result!(result);
+    assert!(converted.is_err());
+
+    let err = converted.unwrap_err();
+    assert_eq!(err.kind.message_id(), "JSON-00009");
+}
+
+#[test]
+fn test_convert_result_eof_while_parsing_object() {
+    let result: Result<Value, serde_json::Error> = serde_json::from_str("{\"a\":1");
+    let converted = convert_result!(result);
+    assert!(converted.is_err());
+
+    let err = converted.unwrap_err();
+    assert_eq!(err.kind.message_id(), "JSON-00010");
+}
+
+#[test]
+fn test_convert_result_lone_leading_surrogate() {
+    // serde_json raises its "lone leading surrogate" error for an unpaired \uDC00..=\uDFFF escape;
+    // an unpaired \uD800..=\uDBFF escape is reported as an unexpected end of hex escape instead.
+    let result: Result<Value, serde_json::Error> = serde_json::from_str(r#""\uDC00""#);
+    let converted = convert_result!(result);
+    assert!(converted.is_err());
+
+    let err = converted.unwrap_err();
+    assert_eq!(err.kind.message_id(), "JSON-00005");
+}
+
+#[test]
+fn test_convert_result_key_must_be_string() {
+    let result: Result<Value, serde_json::Error> = serde_json::from_str("{a:1}");
+    let converted = convert_result!(result);
+    assert!(converted.is_err());
+
+    let err = converted.unwrap_err();
+    assert_eq!(err.kind.message_id(), "JSON-00006");
+}
+
+#[test]
+fn test_convert_result_expected_colon() {
+    let result: Result<Value, serde_json::Error> = serde_json::from_str("{\"a\" 1}");
+    let converted = convert_result!(result);
+    assert!(converted.is_err());
+
+    let err = converted.unwrap_err();
+    assert_eq!(err.kind.message_id(), "JSON-00007");
+}
+
+#[test]
+fn test_convert_result_expected_comma() {
+    let result: Result<Value, serde_json::Error> = serde_json::from_str("{\"a\":1 \"b\":2}");
+    let converted = convert_result!(result);
+    assert!(converted.is_err());
+
+    let err = converted.unwrap_err();
+    assert_eq!(err.kind.message_id(), "JSON-00008");
+}
+
+#[derive(serde::Deserialize)]
+struct Dependency {
+    version: u32,
+}
+
+#[derive(serde::Deserialize)]
+struct Manifest {
+    dependencies: BTreeMap<String, Dependency>,
+}
+
+#[test]
+fn test_convert_result_at_success() {
+    let input = r#"{"dependencies":{"serde":{"version":1}}}"#;
+    let converted: Result<Manifest, cdumay_core::Error> = convert_result_at!(input, Manifest);
+    assert!(converted.is_ok());
+}
+
+#[test]
+fn test_convert_result_at_records_nested_path() {
+    let input = r#"{"dependencies":{"serde":{"version":"1.0"}}}"#;
+    let converted: Result<Manifest, cdumay_core::Error> = convert_result_at!(input, Manifest);
+    assert!(converted.is_err());
+
+    let err = converted.unwrap_err();
+    assert_eq!(err.details.get("json.path"), Some(&serde_value::Value::String("dependencies.serde.version".to_string())));
+    assert!(err.message.contains("dependencies.serde.version"));
+}
+
+#[test]
+fn test_convert_result_at_rejects_trailing_characters() {
+    let input = r#"{"dependencies":{"serde":{"version":1}}} garbage"#;
+    let converted: Result<Manifest, cdumay_core::Error> = convert_result_at!(input, Manifest);
+    assert!(converted.is_err());
+
+    let err = converted.unwrap_err();
+    assert_eq!(err.kind.message_id(), "JSON-00002");
+}
+
+#[test]
+fn test_convert_with_input_truncates_on_char_boundary() {
+    // 9 ASCII bytes followed by a 3-byte '€', so a byte-oriented truncation at 10 would split it.
+    let input = format!("{}€ invalid json", "a".repeat(9));
+    let result: Result<Value, serde_json::Error> = serde_json::from_str(&input);
+    let err = result.unwrap_err();
+
+    let converted = JsonErrorConverter::convert_with_input(&err, &input, "Test error".to_string(), BTreeMap::new(), 10);
+    match converted.details.get("json.raw_input") {
+        Some(serde_value::Value::String(raw)) => {
+            assert!(input.starts_with(raw.as_str()));
+            assert!(raw.len() <= 10);
+            assert!(raw.is_char_boundary(raw.len()));
+        }
+        other => panic!("expected json.raw_input to be a String, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_is_recoverable_eof_is_true() {
+    let input = "{\"a\":1";
+    let err: serde_json::Error = serde_json::from_str::<Value>(input).unwrap_err();
+    assert!(JsonErrorConverter::is_recoverable(&err));
+
+    let result: Result<Value, serde_json::Error> = serde_json::from_str(input);
+    let converted_err = convert_result!(result).unwrap_err();
+    assert!(JsonErrorConverter::is_error_recoverable(&converted_err));
+}
+
+#[test]
+fn test_is_recoverable_syntax_is_false() {
+    let input = "invalid json";
+    let err: serde_json::Error = serde_json::from_str::<Value>(input).unwrap_err();
+    assert!(!JsonErrorConverter::is_recoverable(&err));
+
+    let result: Result<Value, serde_json::Error> = serde_json::from_str(input);
+    let converted_err = convert_result!(result).unwrap_err();
+    assert!(!JsonErrorConverter::is_error_recoverable(&converted_err));
+}